@@ -87,6 +87,15 @@ do
     cargo test --verbose --features="$feature"
 done
 
+# tokio-codec implies std so it is kept out of FEATURES (used for the no-std build). It also
+# needs Rust 1.70 (the rust-version of tokio-util and tokio) so only test it on toolchains that
+# support it.
+if [ "$DO_TOKIO_CODEC" = true ]
+then
+    echo "********* Testing tokio-codec *************"
+    cargo test --verbose --features="tokio-codec"
+fi
+
 cargo run --example ecdsa-psbt --features=bitcoinconsensus
 cargo run --example taproot-psbt --features=rand-std,bitcoinconsensus
 
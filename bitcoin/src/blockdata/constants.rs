@@ -9,6 +9,7 @@
 //!
 
 use core::default::Default;
+use core::fmt;
 
 use bitcoin_internals::impl_array_newtype;
 use hex_lit::hex;
@@ -20,6 +21,8 @@ use crate::blockdata::locktime::absolute;
 use crate::blockdata::transaction::{OutPoint, Transaction, TxOut, TxIn, Sequence};
 use crate::blockdata::block::{self, Block};
 use crate::blockdata::witness::Witness;
+use crate::error::impl_std_error;
+use crate::hash_types::BlockHash;
 use crate::network::constants::Network;
 use crate::pow::CompactTarget;
 use crate::internal_macros::impl_bytes_newtype;
@@ -99,6 +102,12 @@ fn bitcoin_genesis_tx() -> Transaction {
     ret
 }
 
+/// Txid of the genesis coinbase transaction (shared by all networks), in internal byte order.
+pub(crate) const GENESIS_COINBASE_TXID: [u8; 32] = [
+    0x3b, 0xa3, 0xed, 0xfd, 0x7a, 0x7b, 0x12, 0xb2, 0x7a, 0xc7, 0x2c, 0x3e, 0x67, 0x76, 0x8f, 0x61,
+    0x7f, 0xc8, 0x1b, 0xc3, 0x88, 0x8a, 0x51, 0x32, 0x3a, 0x9f, 0xb8, 0xaa, 0x4b, 0x1e, 0x5e, 0x4a,
+];
+
 /// Constructs and returns the genesis block.
 pub fn genesis_block(network: Network) -> Block {
     let txdata = vec![bitcoin_genesis_tx()];
@@ -160,6 +169,57 @@ pub fn genesis_block(network: Network) -> Block {
     }
 }
 
+/// Constructs the `network` genesis block with its header `bits`, `time` and `nonce` overridden.
+///
+/// The coinbase transaction and the remaining header fields are those of [`genesis_block`]. This
+/// is meant for test harnesses running a custom chain (e.g. regtest with a different starting
+/// difficulty); the returned block does not hash to the network's [`ChainHash`].
+pub fn genesis_block_with_params(network: Network, bits: CompactTarget, time: u32, nonce: u32) -> Block {
+    let mut block = genesis_block(network);
+    block.header.bits = bits;
+    block.header.time = time;
+    block.header.nonce = nonce;
+    block
+}
+
+/// Checks that the `network` genesis block hashes to the network's [`ChainHash`].
+///
+/// [`genesis_block`] assembles the block from its individual fields while [`ChainHash`] is a
+/// hard-coded constant; this recomputes the former and compares it against the latter so that
+/// the two can not silently drift apart.
+pub fn verify_genesis(network: Network) -> Result<(), GenesisMismatchError> {
+    let computed = genesis_block(network).block_hash();
+    if computed.as_byte_array() == &ChainHash::using_genesis_block(network).0 {
+        Ok(())
+    } else {
+        Err(GenesisMismatchError { network, computed })
+    }
+}
+
+/// The genesis block of a network does not hash to the network's [`ChainHash`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenesisMismatchError {
+    /// The network whose genesis block was checked.
+    pub network: Network,
+    /// The hash of the genesis block as returned by [`genesis_block`].
+    pub computed: BlockHash,
+}
+
+impl fmt::Display for GenesisMismatchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "genesis block for {} hashes to {}, expected {}",
+            self.network,
+            self.computed,
+            // Display both as block hashes so they are in the same (reversed) byte order.
+            BlockHash::from_byte_array(ChainHash::using_genesis_block(self.network).0)
+        )
+    }
+}
+
+impl_std_error!(GenesisMismatchError);
+
 /// The uniquely identifying hash of the target blockchain.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ChainHash([u8; 32]);
@@ -194,6 +254,7 @@ mod test {
     use crate::consensus::encode::serialize;
     use crate::blockdata::locktime::absolute;
     use crate::internal_macros::hex;
+    use crate::pow::Target;
 
     #[test]
     fn bitcoin_genesis_first_transaction() {
@@ -254,6 +315,44 @@ mod test {
         assert_eq!(gen.header.block_hash().to_string(), "00000008819873e925422c1ff0f99f7cc9bbb232af63a077a480a3633bee1ef6");
     }
 
+    #[test]
+    fn regtest_genesis_with_params() {
+        let bits = CompactTarget::from_consensus(0x1e0ffff0);
+        let gen = genesis_block(Network::Regtest);
+        let custom = genesis_block_with_params(Network::Regtest, bits, 1296688603, 7);
+
+        assert_eq!(custom.header.bits, bits);
+        assert_eq!(custom.header.target(), Target::from_compact(bits));
+        assert_ne!(custom.header.target(), gen.header.target());
+        assert_eq!(custom.header.time, 1296688603);
+        assert_eq!(custom.header.nonce, 7);
+
+        assert_eq!(custom.txdata, gen.txdata);
+        assert_eq!(custom.header.merkle_root, gen.header.merkle_root);
+        assert!(custom.check_merkle_root());
+        assert_ne!(custom.block_hash(), gen.block_hash());
+        assert_eq!(custom.block_hash(), custom.header.block_hash());
+    }
+
+    #[test]
+    fn genesis_merkle_root() {
+        for network in &[Network::Bitcoin, Network::Testnet, Network::Signet, Network::Regtest] {
+            let gen = genesis_block(*network);
+            assert_eq!(gen.compute_merkle_root(), Some(gen.header.merkle_root));
+        }
+    }
+
+    #[test]
+    fn genesis_mismatch_error() {
+        let err = GenesisMismatchError { network: Network::Regtest, computed: BlockHash::all_zeros() };
+        assert_eq!(
+            err.to_string(),
+            "genesis block for regtest hashes to \
+             0000000000000000000000000000000000000000000000000000000000000000, \
+             expected 0f9188f13cb7b2c71f2a335e3a4fc328bf5beb436012afca590b1a11466e2206"
+        );
+    }
+
     // The *_chain_hash tests are sanity/regression tests, they verify that the const byte array
     // representing the genesis block is the same as that created by hashing the genesis block.
     fn chain_hash_and_genesis_block(network: Network) {
@@ -271,6 +370,8 @@ mod test {
         // Compare strings because the spec specifically states how the chain hash must encode to hex.
         assert_eq!(got, want);
 
+        assert_eq!(verify_genesis(network), Ok(()));
+
         match network {
             Network::Bitcoin => {},
             Network::Testnet => {},
@@ -292,6 +292,27 @@ impl Script {
             && self.0[1] == OP_PUSHBYTES_32.to_u8()
     }
 
+    /// Checks whether a script pubkey is a Litecoin MWEB peg-in output.
+    ///
+    /// Peg-in outputs are witness version 9 programs whose 32-byte program is the ID of the
+    /// kernel that creates the pegged-in coins in the extension block.
+    #[inline]
+    pub fn is_mweb_pegin(&self) -> bool {
+        self.0.len() == 34
+            && self.witness_version() == Some(WitnessVersion::V9)
+            && self.0[1] == OP_PUSHBYTES_32.to_u8()
+    }
+
+    /// Returns the MWEB kernel ID if this script is a peg-in output.
+    #[inline]
+    pub fn mweb_pegin_kernel_id(&self) -> Option<&[u8; 32]> {
+        if self.is_mweb_pegin() {
+            Some(self.0[2..].try_into().expect("is_mweb_pegin checks the length"))
+        } else {
+            None
+        }
+    }
+
     /// Check if this is an OP_RETURN output.
     #[inline]
     pub fn is_op_return (&self) -> bool {
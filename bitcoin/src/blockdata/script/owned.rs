@@ -138,6 +138,12 @@ impl ScriptBuf {
         ScriptBuf::new_witness_program_unchecked(WitnessVersion::V1, output_key.serialize())
     }
 
+    /// Generates a Litecoin MWEB peg-in scriptPubkey for the given kernel ID.
+    pub fn new_mweb_pegin(kernel_id: &[u8; 32]) -> Self {
+        // kernel ID is 32 bytes long, so it's safe to use `new_witness_program_unchecked` (MWEB peg-in, v9)
+        ScriptBuf::new_witness_program_unchecked(WitnessVersion::V9, kernel_id)
+    }
+
     /// Generates P2WSH-type of scriptPubkey with a given [`WitnessProgram`].
     pub fn new_witness_program(witness_program: &WitnessProgram) -> Self {
         Builder::new()
@@ -149,8 +155,8 @@ impl ScriptBuf {
     /// Generates P2WSH-type of scriptPubkey with a given [`WitnessVersion`] and the program bytes.
     /// Does not do any checks on version or program length.
     ///
-    /// Convenience method used by `new_v0_p2wpkh`, `new_v0_p2wsh`, `new_v1_p2tr`, and
-    /// `new_v1_p2tr_tweaked`.
+    /// Convenience method used by `new_v0_p2wpkh`, `new_v0_p2wsh`, `new_v1_p2tr`,
+    /// `new_v1_p2tr_tweaked` and `new_mweb_pegin`.
     fn new_witness_program_unchecked<T: AsRef<PushBytes>>(version: WitnessVersion, program: T) -> Self {
         let program = program.as_ref();
         debug_assert!(program.len() >= 2 && program.len() <= 40);
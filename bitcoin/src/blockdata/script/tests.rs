@@ -571,6 +571,33 @@ fn defult_dust_value_tests() {
     assert_eq!(script_p2pkh.dust_value(), crate::Amount::from_sat(546));
 }
 
+#[test]
+fn mweb_pegin() {
+    let kernel_id = [0x08; 32];
+    let pegin = Builder::new().push_int(9).push_slice(kernel_id).into_script();
+    assert!(pegin.is_witness_program());
+    assert!(pegin.is_mweb_pegin());
+    assert_eq!(pegin.mweb_pegin_kernel_id(), Some(&kernel_id));
+    assert_eq!(ScriptBuf::new_mweb_pegin(&kernel_id), pegin);
+    assert_eq!(pegin.as_bytes()[..2], [OP_PUSHNUM_9.to_u8(), OP_PUSHBYTES_32.to_u8()]);
+
+    // Wrong program length.
+    let short = Builder::new().push_int(9).push_slice([0x08; 31]).into_script();
+    assert!(!short.is_mweb_pegin());
+    assert_eq!(short.mweb_pegin_kernel_id(), None);
+    let long = Builder::new().push_int(9).push_slice([0x08; 33]).into_script();
+    assert!(!long.is_mweb_pegin());
+    // Wrong witness version, the same program is a taproot output.
+    let v1 = Builder::new().push_int(1).push_slice(kernel_id).into_script();
+    assert!(v1.is_v1_p2tr());
+    assert!(!v1.is_mweb_pegin());
+
+    let p2wpkh = Builder::new().push_int(0).push_slice([42; 20]).into_script();
+    assert!(!p2wpkh.is_mweb_pegin());
+    let op_return = Builder::new().push_opcode(OP_RETURN).push_slice(kernel_id).into_script();
+    assert!(!op_return.is_mweb_pegin());
+}
+
 #[test]
 #[cfg(feature = "serde")]
 fn test_script_serde_human_and_not() {
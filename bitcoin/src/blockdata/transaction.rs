@@ -23,7 +23,7 @@ use bitcoin_internals::write_err;
 
 use crate::hashes::{self, Hash, sha256d};
 
-use crate::blockdata::constants::WITNESS_SCALE_FACTOR;
+use crate::blockdata::constants::{GENESIS_COINBASE_TXID, WITNESS_SCALE_FACTOR};
 #[cfg(feature="bitcoinconsensus")] use crate::blockdata::script;
 use crate::blockdata::script::{ScriptBuf, Script};
 use crate::blockdata::witness::Witness;
@@ -32,6 +32,7 @@ use crate::blockdata::locktime::relative;
 use crate::consensus::{encode, Decodable, Encodable};
 use crate::crypto::sighash::LegacySighash;
 use crate::hash_types::{Txid, Wtxid};
+use crate::network::constants::Network;
 use crate::VarInt;
 use crate::internal_macros::impl_consensus_encoding;
 use crate::parse::impl_parse_str_from_int_infallible;
@@ -1002,6 +1003,17 @@ impl Transaction {
         self.input.len() == 1 && self.input[0].previous_output.is_null()
     }
 
+    /// Checks if this is the coinbase transaction of a genesis block.
+    ///
+    /// The output of the genesis coinbase is not part of the UTXO set and can never be spent, so
+    /// code tracking unspent outputs should use this to skip it.
+    ///
+    /// All networks currently share the same genesis coinbase, the network argument is accepted
+    /// for API stability in case that changes.
+    pub fn is_genesis_coinbase(&self, _network: Network) -> bool {
+        self.is_coin_base() && self.txid().as_byte_array() == &GENESIS_COINBASE_TXID
+    }
+
     /// Returns `true` if the transaction itself opted in to be BIP-125-replaceable (RBF).
     ///
     /// # Warning
@@ -1443,6 +1455,22 @@ mod tests {
         assert!(!tx.is_coin_base());
     }
 
+    #[test]
+    fn test_is_genesis_coinbase() {
+        use crate::blockdata::constants;
+
+        for network in &[Network::Bitcoin, Network::Testnet, Network::Signet, Network::Regtest] {
+            let genesis = constants::genesis_block(*network);
+            assert!(genesis.txdata[0].is_genesis_coinbase(*network));
+        }
+
+        // Coinbase of block 1.
+        let tx_bytes = hex!("01000000010000000000000000000000000000000000000000000000000000000000000000ffffffff0704ffff001d0104ffffffff0100f2052a0100000043410496b538e853519c726a2c91e61ec11600ae1390813a627c66fb8be7947be63c52da7589379515d4e0a604f8141781e62294721166bf621e73a82cbf2342c858eeac00000000");
+        let tx: Transaction = deserialize(&tx_bytes).unwrap();
+        assert!(tx.is_coin_base());
+        assert!(!tx.is_genesis_coinbase(Network::Bitcoin));
+    }
+
     #[test]
     fn test_nonsegwit_transaction() {
         let tx_bytes = hex!("0100000001a15d57094aa7a21a28cb20b59aab8fc7d1149a3bdbcddba9c622e4f5f6a99ece010000006c493046022100f93bb0e7d8db7bd46e40132d1f8242026e045f03a0efe71bbb8e3f475e970d790221009337cd7f1f929f00cc6ff01f03729b069a7c21b59b1736ddfee5db5946c5da8c0121033b9b137ee87d5a812d6f506efdd37f0affa7ffc310711c06c7f3e097c9447c52ffffffff0100e1f505000000001976a9140389035a9225b3839e2bbf32d826a1e222031fd888ac00000000");
@@ -30,6 +30,10 @@
 //!                            `std::error::Error`. At this time there's a hack to
 //!                            achieve the same without this feature but it could
 //!                            happen the implementations diverge one day.
+//! * `tokio-codec` - (dependency), implements `tokio_util` codec traits for
+//!                   framing network messages. Implies `std`. Requires Rust 1.70
+//!                   (the `rust-version` of `tokio-util` and `tokio`, above
+//!                   the crate's MSRV).
 
 #![cfg_attr(all(not(feature = "std"), not(test)), no_std)]
 // Experimental features we need.
@@ -96,6 +100,7 @@ pub(crate) mod crypto;
 pub mod error;
 pub mod hash_types;
 pub mod merkle_tree;
+pub mod mimblewimble;
 pub mod policy;
 pub mod pow;
 pub mod psbt;
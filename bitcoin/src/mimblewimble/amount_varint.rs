@@ -0,0 +1,92 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! MWEB amount variable-length integers.
+//!
+//! MWEB serializes amounts (e.g. kernel fees and peg-in/peg-out values) with Bitcoin Core's
+//! `VARINT` format rather than the `CompactSize` used by [`VarInt`](crate::VarInt). Each byte
+//! carries 7 bits, most significant group first, with the high bit set on all but the last byte.
+//! Every continuation group is stored minus one so that each value has exactly one encoding.
+//!
+
+use crate::consensus::encode::{Error, ReadExt};
+use crate::io;
+
+/// Maximum length of an encoded `u64`.
+const MAX_LEN: usize = 10;
+
+/// Reads an MWEB amount varint.
+///
+/// Fails with [`Error::ParseFailed`] if the encoded value does not fit in a `u64`.
+pub fn read<R: io::Read + ?Sized>(reader: &mut R) -> Result<u64, Error> {
+    let mut n = 0u64;
+    loop {
+        let byte = reader.read_u8()?;
+        if n > u64::max_value() >> 7 {
+            return Err(Error::ParseFailed("MWEB amount varint overflows u64"));
+        }
+        n = (n << 7) | u64::from(byte & 0x7F);
+        if byte & 0x80 == 0 {
+            return Ok(n);
+        }
+        if n == u64::max_value() {
+            return Err(Error::ParseFailed("MWEB amount varint overflows u64"));
+        }
+        n += 1;
+    }
+}
+
+/// Writes `value` as an MWEB amount varint, returning the number of bytes written.
+pub fn write<W: io::Write + ?Sized>(writer: &mut W, value: u64) -> Result<usize, io::Error> {
+    let mut buf = [0u8; MAX_LEN];
+    let mut n = value;
+    let mut len = 0;
+    loop {
+        buf[MAX_LEN - 1 - len] = (n & 0x7F) as u8 | if len > 0 { 0x80 } else { 0x00 };
+        len += 1;
+        if n <= 0x7F {
+            break;
+        }
+        n = (n >> 7) - 1;
+    }
+    writer.write_all(&buf[MAX_LEN - len..])?;
+    Ok(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::internal_macros::hex;
+    use crate::prelude::*;
+
+    #[test]
+    fn amount_varint_vectors() {
+        // Examples from the `VARINT` documentation in Bitcoin Core's serialize.h.
+        let vectors: &[(u64, Vec<u8>)] = &[
+            (0, hex!("00")),
+            (127, hex!("7f")),
+            (128, hex!("8000")),
+            (255, hex!("807f")),
+            (16383, hex!("fe7f")),
+            (16384, hex!("ff00")),
+            (1 << 32, hex!("8efefeff00")),
+            (u64::max_value(), hex!("80fefefefefefefefe7f")),
+        ];
+
+        for (value, bytes) in vectors {
+            let mut buf = Vec::new();
+            assert_eq!(write(&mut buf, *value).unwrap(), bytes.len());
+            assert_eq!(&buf, bytes);
+            assert_eq!(read(&mut &bytes[..]).unwrap(), *value);
+        }
+    }
+
+    #[test]
+    fn amount_varint_overflow() {
+        // One more than u64::MAX.
+        assert!(read(&mut &hex!("80fefefefefefefeff00")[..]).is_err());
+        // Too many continuation bytes.
+        assert!(read(&mut &hex!("ffffffffffffffffffff00")[..]).is_err());
+        // Truncated.
+        assert!(read(&mut &hex!("80")[..]).is_err());
+    }
+}
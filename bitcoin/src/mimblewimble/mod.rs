@@ -0,0 +1,326 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! MimbleWimble Extension Block (MWEB) support.
+//!
+//! This module provides types used by Litecoin's MWEB, as specified in
+//! [LIP-0004](https://github.com/litecoin-project/lips/blob/master/lip-0004.mediawiki).
+//!
+
+pub mod amount_varint;
+
+use core::fmt;
+use core::str::FromStr;
+
+use bech32::{FromBase32, ToBase32, WriteBase32};
+use bitcoin_internals::write_err;
+use secp256k1::{KeyPair, PublicKey};
+
+use crate::network::constants::Network;
+use crate::prelude::*;
+
+/// Version of the stealth address encoding, the first 5-bit group of the bech32 data.
+const ADDRESS_VERSION: u8 = 0;
+
+/// An error parsing an [`MwebAddress`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// Bech32 encoding error.
+    Bech32(bech32::Error),
+    /// The human-readable part is not one of the MWEB prefixes.
+    UnknownHrp(String),
+    /// The address uses bech32m, MWEB addresses use bech32.
+    InvalidBech32Variant(bech32::Variant),
+    /// The bech32 payload was empty.
+    EmptyBech32Payload,
+    /// The address version is not supported.
+    UnsupportedVersion(u8),
+    /// The payload is not two compressed public keys.
+    InvalidLength(usize),
+    /// A public key is not valid.
+    Secp256k1(secp256k1::Error),
+    /// The address's network differs from the required one.
+    NetworkValidation {
+        /// Network that was required.
+        required: Network,
+        /// Network the address was encoded for.
+        found: Network,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Bech32(ref e) => write_err!(f, "bech32 address encoding error"; e),
+            Error::UnknownHrp(ref hrp) => write!(f, "unknown MWEB address prefix: {}", hrp),
+            Error::InvalidBech32Variant(v) =>
+                write!(f, "invalid bech32 checksum variant found {:?} when Bech32 was expected", v),
+            Error::EmptyBech32Payload => write!(f, "the bech32 payload was empty"),
+            Error::UnsupportedVersion(v) => write!(f, "unsupported MWEB address version: {}", v),
+            Error::InvalidLength(len) =>
+                write!(f, "MWEB address payload must be 66 bytes in length: length={}", len),
+            Error::Secp256k1(ref e) => write_err!(f, "invalid public key"; e),
+            Error::NetworkValidation { required, found } => write!(
+                f,
+                "MWEB address belongs to network {} which is different from required {}",
+                found, required
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use self::Error::*;
+
+        match self {
+            Bech32(e) => Some(e),
+            Secp256k1(e) => Some(e),
+            UnknownHrp(_)
+            | InvalidBech32Variant(_)
+            | EmptyBech32Payload
+            | UnsupportedVersion(_)
+            | InvalidLength(_)
+            | NetworkValidation { .. } => None,
+        }
+    }
+}
+
+impl From<bech32::Error> for Error {
+    fn from(e: bech32::Error) -> Error { Error::Bech32(e) }
+}
+
+impl From<secp256k1::Error> for Error {
+    fn from(e: secp256k1::Error) -> Error { Error::Secp256k1(e) }
+}
+
+/// An MWEB stealth address.
+///
+/// A stealth address is a pair of public keys: the scan key `A`, used by the receiver to
+/// recognise outputs sent to them, and the spend key `B`, needed to spend those outputs. Senders
+/// derive a fresh one-time output key from both, so outputs to the same address are unlinkable.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct StealthAddress {
+    /// The scan public key (`A`).
+    pub scan_pubkey: PublicKey,
+    /// The spend public key (`B`).
+    pub spend_pubkey: PublicKey,
+}
+
+impl StealthAddress {
+    /// Creates a stealth address from its scan and spend public keys.
+    pub fn new(scan_pubkey: PublicKey, spend_pubkey: PublicKey) -> StealthAddress {
+        StealthAddress { scan_pubkey, spend_pubkey }
+    }
+
+    /// Creates a stealth address from a wallet's scan and spend key pairs.
+    pub fn from_keypairs(scan: &KeyPair, spend: &KeyPair) -> StealthAddress {
+        StealthAddress::new(scan.public_key(), spend.public_key())
+    }
+
+    /// Creates a stealth address from two serialized public keys.
+    pub fn from_slices(scan: &[u8], spend: &[u8]) -> Result<StealthAddress, secp256k1::Error> {
+        Ok(StealthAddress::new(PublicKey::from_slice(scan)?, PublicKey::from_slice(spend)?))
+    }
+
+    /// Serializes the address as the compressed scan key followed by the compressed spend key.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(66);
+        bytes.extend_from_slice(&self.scan_pubkey.serialize());
+        bytes.extend_from_slice(&self.spend_pubkey.serialize());
+        bytes
+    }
+}
+
+/// An MWEB stealth address together with the network it is intended for.
+///
+/// Encoded as bech32 with the `ltcmweb` (mainnet), `tmweb` (testnet and signet) or `rmweb`
+/// (regtest) human-readable part. The data is a version (currently 0) followed by the serialized
+/// [`StealthAddress`].
+///
+/// # Examples
+///
+/// ```
+/// use bitcoin::mimblewimble::MwebAddress;
+/// use bitcoin::network::constants::Network;
+///
+/// let s = "ltcmweb1qqfumuen7l8wthtz45p3ftn58pvrs9xlumvkuu2xet8egzkcklqtesqkxq3legs0d04knq32qd62uqlxct3mcujuvau7202avpxu4cuy7u5l7dx6j";
+/// let address = s.parse::<MwebAddress>().unwrap().require_network(Network::Bitcoin).unwrap();
+/// assert_eq!(address.to_string(), s);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct MwebAddress {
+    /// The network on which this address is usable.
+    pub network: Network,
+    /// The stealth address.
+    pub stealth_address: StealthAddress,
+}
+
+impl MwebAddress {
+    /// Creates an MWEB address for `network`.
+    pub fn new(network: Network, stealth_address: StealthAddress) -> MwebAddress {
+        MwebAddress { network, stealth_address }
+    }
+
+    /// Returns the bech32 human-readable part used for `network`.
+    pub fn hrp(network: Network) -> &'static str {
+        match network {
+            Network::Bitcoin => "ltcmweb",
+            Network::Testnet | Network::Signet => "tmweb",
+            Network::Regtest => "rmweb",
+        }
+    }
+
+    /// Checks whether this address can be used on `network`.
+    ///
+    /// Testnet and signet share the same prefix, so an address parsed as testnet is also valid
+    /// for signet and vice versa.
+    pub fn is_valid_for_network(&self, network: Network) -> bool {
+        MwebAddress::hrp(self.network) == MwebAddress::hrp(network)
+    }
+
+    /// Checks that this address can be used on `required`, returning it with that network.
+    pub fn require_network(self, required: Network) -> Result<MwebAddress, Error> {
+        if self.is_valid_for_network(required) {
+            Ok(MwebAddress { network: required, ..self })
+        } else {
+            Err(Error::NetworkValidation { required, found: self.network })
+        }
+    }
+}
+
+impl fmt::Display for MwebAddress {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let hrp = MwebAddress::hrp(self.network);
+        let mut writer = bech32::Bech32Writer::new(hrp, bech32::Variant::Bech32, f)?;
+        writer.write_u5(bech32::u5::try_from_u8(ADDRESS_VERSION).expect("version is a valid u5"))?;
+        self.stealth_address.to_bytes().write_base32(&mut writer)
+    }
+}
+
+impl FromStr for MwebAddress {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<MwebAddress, Error> {
+        let (hrp, data, variant) = bech32::decode(s)?;
+        let network = match &hrp[..] {
+            "ltcmweb" => Network::Bitcoin,
+            "tmweb" => Network::Testnet, // this may also be signet
+            "rmweb" => Network::Regtest,
+            _ => return Err(Error::UnknownHrp(hrp)),
+        };
+        if variant != bech32::Variant::Bech32 {
+            return Err(Error::InvalidBech32Variant(variant));
+        }
+
+        let (version, payload) = match data.split_first() {
+            Some((version, payload)) => (version.to_u8(), payload),
+            None => return Err(Error::EmptyBech32Payload),
+        };
+        if version != ADDRESS_VERSION {
+            return Err(Error::UnsupportedVersion(version));
+        }
+
+        let bytes = Vec::<u8>::from_base32(payload)?;
+        if bytes.len() != 66 {
+            return Err(Error::InvalidLength(bytes.len()));
+        }
+        let stealth_address = StealthAddress::from_slices(&bytes[..33], &bytes[33..])?;
+
+        Ok(MwebAddress { network, stealth_address })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use secp256k1::{Secp256k1, SecretKey};
+
+    use super::*;
+
+    #[test]
+    fn stealth_address_constructors() {
+        let secp = Secp256k1::new();
+        let scan = KeyPair::from_secret_key(&secp, &SecretKey::from_slice(&[1; 32]).unwrap());
+        let spend = KeyPair::from_secret_key(&secp, &SecretKey::from_slice(&[2; 32]).unwrap());
+
+        let addr = StealthAddress::from_keypairs(&scan, &spend);
+        assert_eq!(addr.scan_pubkey, scan.public_key());
+        assert_eq!(addr.spend_pubkey, spend.public_key());
+        assert_eq!(addr, StealthAddress::new(scan.public_key(), spend.public_key()));
+
+        let bytes = addr.to_bytes();
+        assert_eq!(bytes.len(), 66);
+        assert_eq!(StealthAddress::from_slices(&bytes[..33], &bytes[33..]), Ok(addr));
+        assert!(StealthAddress::from_slices(&bytes[..32], &bytes[33..]).is_err());
+    }
+
+    fn g_2g() -> StealthAddress {
+        let secp = Secp256k1::new();
+        let mut one = [0; 32];
+        one[31] = 1;
+        let mut two = [0; 32];
+        two[31] = 2;
+        StealthAddress::new(
+            PublicKey::from_secret_key(&secp, &SecretKey::from_slice(&one).unwrap()),
+            PublicKey::from_secret_key(&secp, &SecretKey::from_slice(&two).unwrap()),
+        )
+    }
+
+    #[test]
+    fn mweb_address_roundtrip() {
+        let data = "qqfumuen7l8wthtz45p3ftn58pvrs9xlumvkuu2xet8egzkcklqtesqkxq3legs0d04knq32qd62uqlxct3mcujuvau7202avpxu4cuy7u5";
+        let vectors = [
+            (Network::Bitcoin, format!("ltcmweb1{}l7dx6j", data)),
+            (Network::Testnet, format!("tmweb1{}7hv3x9", data)),
+            (Network::Regtest, format!("rmweb1{}nlfgg5", data)),
+        ];
+        for (network, s) in vectors.iter() {
+            let address = MwebAddress::new(*network, g_2g());
+            assert_eq!(&address.to_string(), s);
+            assert_eq!(s.parse::<MwebAddress>(), Ok(address));
+            assert_eq!(s.to_uppercase().parse::<MwebAddress>(), Ok(address));
+        }
+
+        let signet = MwebAddress::new(Network::Signet, g_2g());
+        assert_eq!(signet.to_string(), vectors[1].1);
+    }
+
+    #[test]
+    fn mweb_address_network_validation() {
+        let testnet = MwebAddress::new(Network::Testnet, g_2g());
+        assert!(testnet.is_valid_for_network(Network::Signet));
+        assert_eq!(testnet.require_network(Network::Signet).unwrap().network, Network::Signet);
+        assert_eq!(
+            testnet.require_network(Network::Bitcoin),
+            Err(Error::NetworkValidation { required: Network::Bitcoin, found: Network::Testnet })
+        );
+        assert!(!MwebAddress::new(Network::Regtest, g_2g()).is_valid_for_network(Network::Testnet));
+    }
+
+    #[test]
+    fn mweb_address_invalid() {
+        let data = "qfumuen7l8wthtz45p3ftn58pvrs9xlumvkuu2xet8egzkcklqtesqkxq3legs0d04knq32qd62uqlxct3mcujuvau7202avpxu4cuy7u5";
+        // Bech32m checksum.
+        assert_eq!(
+            format!("ltcmweb1q{}2za2ls", data).parse::<MwebAddress>(),
+            Err(Error::InvalidBech32Variant(bech32::Variant::Bech32m))
+        );
+        // Version 1.
+        assert_eq!(
+            format!("ltcmweb1p{}eehl5a", data).parse::<MwebAddress>(),
+            Err(Error::UnsupportedVersion(1))
+        );
+        // Scan key only.
+        assert_eq!(
+            "ltcmweb1qqfumuen7l8wthtz45p3ftn58pvrs9xlumvkuu2xet8egzkcklqtesq849er".parse::<MwebAddress>(),
+            Err(Error::InvalidLength(33))
+        );
+        // Segwit address.
+        assert_eq!(
+            "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4".parse::<MwebAddress>(),
+            Err(Error::UnknownHrp("bc".to_owned()))
+        );
+    }
+}
@@ -0,0 +1,154 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Tokio codec for P2P message framing.
+//!
+//! This module provides [`MessageCodec`], an implementation of the `tokio_util`
+//! [`Encoder`] and [`Decoder`] traits for [`RawNetworkMessage`], so that a raw
+//! TCP stream can be turned into a stream of network messages with
+//! [`tokio_util::codec::Framed`].
+//!
+
+use bytes::{Buf, BytesMut};
+use tokio_util::codec::{Decoder, Encoder};
+
+use crate::consensus::encode;
+use crate::network::constants::Magic;
+use crate::network::message::{RawNetworkMessage, MAX_MSG_SIZE};
+use crate::network::stream::frame_len;
+pub use crate::network::stream::{Error, HEADER_SIZE};
+
+/// Encodes and decodes [`RawNetworkMessage`]s for a single network.
+///
+/// Frames are rejected as soon as their header is available if the magic does not match or
+/// the announced payload exceeds the configured maximum, so that a misbehaving peer cannot
+/// make us buffer an arbitrary amount of data. Such errors leave the stream out of sync and
+/// the connection should be dropped. The payload checksum is verified before the message is
+/// returned; messages that fail to decode (e.g. due to a bad checksum) are discarded before the
+/// error is returned, the same as with [`StreamDecoder`](crate::network::stream::StreamDecoder).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageCodec {
+    magic: Magic,
+    max_payload_size: usize,
+}
+
+impl MessageCodec {
+    /// Creates a codec for the network identified by `magic`, limiting payloads to [`MAX_MSG_SIZE`].
+    pub fn new(magic: Magic) -> Self { MessageCodec { magic, max_payload_size: MAX_MSG_SIZE } }
+
+    /// Sets the maximum accepted payload size in bytes.
+    pub fn with_max_payload_size(mut self, max_payload_size: usize) -> Self {
+        self.max_payload_size = max_payload_size;
+        self
+    }
+
+    /// Returns the magic this codec accepts.
+    pub fn magic(&self) -> Magic { self.magic }
+
+    /// Returns the maximum accepted payload size in bytes.
+    pub fn max_payload_size(&self) -> usize { self.max_payload_size }
+}
+
+impl Decoder for MessageCodec {
+    type Item = RawNetworkMessage;
+    type Error = Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        let frame_len = match frame_len(src, self.magic, self.max_payload_size)? {
+            Some(len) => len,
+            None => return Ok(None),
+        };
+        if src.len() < frame_len {
+            src.reserve(frame_len - src.len());
+            return Ok(None);
+        }
+
+        let message = encode::deserialize(&src[..frame_len]);
+        src.advance(frame_len);
+        Ok(Some(message?))
+    }
+}
+
+impl Encoder<RawNetworkMessage> for MessageCodec {
+    type Error = Error;
+
+    fn encode(&mut self, item: RawNetworkMessage, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        dst.extend_from_slice(&encode::serialize(&item));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::message::NetworkMessage;
+
+    fn ping() -> RawNetworkMessage {
+        RawNetworkMessage { magic: Magic::BITCOIN, payload: NetworkMessage::Ping(100) }
+    }
+
+    #[test]
+    fn roundtrip() {
+        let mut codec = MessageCodec::new(Magic::BITCOIN);
+        let mut buf = BytesMut::new();
+        codec.encode(ping(), &mut buf).unwrap();
+        codec.encode(ping(), &mut buf).unwrap();
+
+        assert_eq!(codec.decode(&mut buf).unwrap(), Some(ping()));
+        assert_eq!(codec.decode(&mut buf).unwrap(), Some(ping()));
+        assert_eq!(codec.decode(&mut buf).unwrap(), None);
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn partial_frame() {
+        let mut codec = MessageCodec::new(Magic::BITCOIN);
+        let bytes = encode::serialize(&ping());
+
+        let mut buf = BytesMut::new();
+        for &byte in &bytes[..bytes.len() - 1] {
+            buf.extend_from_slice(&[byte]);
+            assert_eq!(codec.decode(&mut buf).unwrap(), None);
+        }
+        buf.extend_from_slice(&bytes[bytes.len() - 1..]);
+        assert_eq!(codec.decode(&mut buf).unwrap(), Some(ping()));
+    }
+
+    #[test]
+    fn wrong_magic() {
+        let mut codec = MessageCodec::new(Magic::TESTNET);
+        let mut buf = BytesMut::from(&encode::serialize(&ping())[..]);
+        match codec.decode(&mut buf) {
+            Err(Error::WrongMagic { expected, actual }) => {
+                assert_eq!(expected, Magic::TESTNET);
+                assert_eq!(actual, Magic::BITCOIN);
+            }
+            res => panic!("unexpected result: {:?}", res),
+        }
+    }
+
+    #[test]
+    fn oversized_payload() {
+        let mut codec = MessageCodec::new(Magic::BITCOIN).with_max_payload_size(4);
+        // Only the header is needed to reject the message.
+        let mut buf = BytesMut::from(&encode::serialize(&ping())[..HEADER_SIZE]);
+        match codec.decode(&mut buf) {
+            Err(Error::OversizedPayload { size: 8, max: 4 }) => {}
+            res => panic!("unexpected result: {:?}", res),
+        }
+    }
+
+    #[test]
+    fn bad_checksum_is_skipped() {
+        let mut codec = MessageCodec::new(Magic::BITCOIN);
+        let mut bytes = encode::serialize(&ping());
+        bytes[20] ^= 0xff;
+        let mut buf = BytesMut::from(&bytes[..]);
+        codec.encode(ping(), &mut buf).unwrap();
+
+        match codec.decode(&mut buf) {
+            Err(Error::Decode(encode::Error::InvalidChecksum { .. })) => {}
+            res => panic!("unexpected result: {:?}", res),
+        }
+        assert_eq!(codec.decode(&mut buf).unwrap(), Some(ping()));
+    }
+}
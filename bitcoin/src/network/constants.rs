@@ -60,6 +60,12 @@ use crate::prelude::{String, ToOwned};
 /// 60001 - Support `pong` message and nonce in `ping` message
 pub const PROTOCOL_VERSION: u32 = 70001;
 
+/// Version of the P2P protocol spoken by current Litecoin Core (0.21.3 and later).
+///
+/// On top of the features listed for [`PROTOCOL_VERSION`] up to 70016:
+/// 70017 - Support MWEB light client messages
+pub const LITECOIN_PROTOCOL_VERSION: u32 = 70017;
+
 /// The cryptocurrency network to act on.
 #[derive(Copy, PartialEq, Eq, PartialOrd, Ord, Clone, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -431,6 +437,10 @@ impl ServiceFlags {
     /// See BIP159 for details on how this is implemented.
     pub const NETWORK_LIMITED: ServiceFlags = ServiceFlags(1 << 10);
 
+    /// MWEB indicates that a node can be asked for blocks and transactions including their MWEB
+    /// (MimbleWimble Extension Block) data.
+    pub const MWEB: ServiceFlags = ServiceFlags(1 << 24);
+
     // NOTE: When adding new flags, remember to update the Display impl accordingly.
 
     /// Add [ServiceFlags] together.
@@ -490,6 +500,7 @@ impl fmt::Display for ServiceFlags {
         write_flag!(WITNESS);
         write_flag!(COMPACT_FILTERS);
         write_flag!(NETWORK_LIMITED);
+        write_flag!(MWEB);
         // If there are unknown flags left, we append them in hex.
         if flags != ServiceFlags::NONE {
             if !first {
@@ -587,6 +598,7 @@ mod tests {
             ServiceFlags::WITNESS,
             ServiceFlags::COMPACT_FILTERS,
             ServiceFlags::NETWORK_LIMITED,
+            ServiceFlags::MWEB,
         ];
 
         let mut flags = ServiceFlags::NONE;
@@ -616,6 +628,8 @@ mod tests {
         assert_eq!("ServiceFlags(NETWORK|BLOOM|WITNESS)", flag.to_string());
         let flag = ServiceFlags::WITNESS | 0xf0.into();
         assert_eq!("ServiceFlags(WITNESS|COMPACT_FILTERS|0xb0)", flag.to_string());
+        let flag = ServiceFlags::NETWORK | ServiceFlags::WITNESS | ServiceFlags::MWEB;
+        assert_eq!("ServiceFlags(NETWORK|WITNESS|MWEB)", flag.to_string());
     }
 
     #[test]
@@ -32,6 +32,10 @@ pub enum Inventory {
     WitnessTransaction(Txid),
     /// Witness Block
     WitnessBlock(BlockHash),
+    /// Witness Transaction including its MWEB data
+    MwebTransaction(Txid),
+    /// Witness Block including its MWEB extension block
+    MwebBlock(BlockHash),
     /// Unknown inventory type
     Unknown {
         /// The inventory item type.
@@ -57,6 +61,8 @@ impl Encodable for Inventory {
             Inventory::WTx(w) => encode_inv!(5, w),
             Inventory::WitnessTransaction(ref t) => encode_inv!(0x40000001, t),
             Inventory::WitnessBlock(ref b) => encode_inv!(0x40000002, b),
+            Inventory::MwebTransaction(ref t) => encode_inv!(0x60000001, t),
+            Inventory::MwebBlock(ref b) => encode_inv!(0x60000002, b),
             Inventory::Unknown { inv_type: t, hash: ref d } => encode_inv!(t, d),
         })
     }
@@ -74,6 +80,8 @@ impl Decodable for Inventory {
             5 => Inventory::WTx(Decodable::consensus_decode(r)?),
             0x40000001 => Inventory::WitnessTransaction(Decodable::consensus_decode(r)?),
             0x40000002 => Inventory::WitnessBlock(Decodable::consensus_decode(r)?),
+            0x60000001 => Inventory::MwebTransaction(Decodable::consensus_decode(r)?),
+            0x60000002 => Inventory::MwebBlock(Decodable::consensus_decode(r)?),
             tp => Inventory::Unknown { inv_type: tp, hash: Decodable::consensus_decode(r)? },
         })
     }
@@ -127,7 +135,7 @@ impl_consensus_encoding!(GetHeadersMessage, version, locator_hashes, stop_hash);
 
 #[cfg(test)]
 mod tests {
-    use super::{GetBlocksMessage, GetHeadersMessage, Vec};
+    use super::{GetBlocksMessage, GetHeadersMessage, Inventory, Vec};
     use crate::consensus::encode::{deserialize, serialize};
     use crate::hashes::Hash;
     use crate::internal_macros::hex;
@@ -163,4 +171,21 @@ mod tests {
 
         assert_eq!(serialize(&real_decode), from_sat);
     }
+
+    #[test]
+    fn mweb_inventory_test() {
+        let hash = hex!("4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b");
+
+        let mut block = hex!("02000060").to_vec();
+        block.extend_from_slice(&hash);
+        let inv: Inventory = deserialize(&block).unwrap();
+        assert_eq!(inv, Inventory::MwebBlock(Hash::from_byte_array(hash)));
+        assert_eq!(serialize(&inv), block);
+
+        let mut tx = hex!("01000060").to_vec();
+        tx.extend_from_slice(&hash);
+        let inv: Inventory = deserialize(&tx).unwrap();
+        assert_eq!(inv, Inventory::MwebTransaction(Hash::from_byte_array(hash)));
+        assert_eq!(serialize(&inv), tx);
+    }
 }
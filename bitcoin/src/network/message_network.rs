@@ -7,6 +7,8 @@
 //! capabilities.
 //!
 
+use std::time::{SystemTime, UNIX_EPOCH};
+
 use crate::consensus::{encode, Decodable, Encodable, ReadExt};
 use crate::hashes::sha256d;
 use crate::internal_macros::impl_consensus_encoding;
@@ -67,6 +69,104 @@ impl VersionMessage {
     }
 }
 
+/// The user agent advertised by [`VersionMessageBuilder`] unless overridden.
+pub const USER_AGENT: &str = concat!("/rust-litecoin:", env!("CARGO_PKG_VERSION"), "/");
+
+/// Builder for a [`VersionMessage`] filled in with defaults suitable for talking to `litecoind`.
+///
+/// Unless overridden the message uses [`constants::LITECOIN_PROTOCOL_VERSION`], advertises the
+/// [`ServiceFlags::WITNESS`] and [`ServiceFlags::MWEB`] services, [`USER_AGENT`], the current
+/// system time, an unspecified sender address, a start height of zero and asks the peer not to
+/// relay transactions.
+#[derive(Clone, Debug)]
+pub struct VersionMessageBuilder {
+    msg: VersionMessage,
+}
+
+impl VersionMessageBuilder {
+    /// Starts building a `version` message for the peer at `receiver`.
+    ///
+    /// The `nonce` must be chosen randomly for every connection, it is what allows peers to
+    /// detect connections to themselves.
+    pub fn new(receiver: Address, nonce: u64) -> VersionMessageBuilder {
+        let services = ServiceFlags::WITNESS | ServiceFlags::MWEB;
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0);
+        let sender = Address { services, address: [0; 8], port: 0 };
+        let msg = VersionMessage::new(
+            services,
+            timestamp,
+            receiver,
+            sender,
+            nonce,
+            USER_AGENT.to_owned(),
+            0,
+        );
+        VersionMessageBuilder {
+            msg: VersionMessage { version: constants::LITECOIN_PROTOCOL_VERSION, ..msg },
+        }
+    }
+
+    /// Starts building a `version` message for the peer at `receiver` with a random nonce.
+    ///
+    /// The nonce is drawn from [`secp256k1::rand::thread_rng`], use [`Self::new`] to supply one.
+    #[cfg(feature = "rand-std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand-std")))]
+    pub fn with_random_nonce(receiver: Address) -> VersionMessageBuilder {
+        use secp256k1::rand::{thread_rng, Rng};
+
+        VersionMessageBuilder::new(receiver, thread_rng().gen())
+    }
+
+    /// Sets the P2P protocol version.
+    pub fn version(mut self, version: u32) -> Self {
+        self.msg.version = version;
+        self
+    }
+
+    /// Sets the services supported by this node, also updating the sender address' services.
+    pub fn services(mut self, services: ServiceFlags) -> Self {
+        self.msg.services = services;
+        self.msg.sender.services = services;
+        self
+    }
+
+    /// Sets the time at which the message is sent.
+    pub fn timestamp(mut self, timestamp: i64) -> Self {
+        self.msg.timestamp = timestamp;
+        self
+    }
+
+    /// Sets the network address of this node.
+    pub fn sender(mut self, sender: Address) -> Self {
+        self.msg.sender = sender;
+        self
+    }
+
+    /// Sets the string describing this node's software.
+    pub fn user_agent<S: Into<String>>(mut self, user_agent: S) -> Self {
+        self.msg.user_agent = user_agent.into();
+        self
+    }
+
+    /// Sets the height of the best chain known to this node.
+    pub fn start_height(mut self, start_height: i32) -> Self {
+        self.msg.start_height = start_height;
+        self
+    }
+
+    /// Sets whether the peer should relay transactions to this node.
+    pub fn relay(mut self, relay: bool) -> Self {
+        self.msg.relay = relay;
+        self
+    }
+
+    /// Returns the finished `version` message.
+    pub fn build(self) -> VersionMessage { self.msg }
+}
+
 impl_consensus_encoding!(
     VersionMessage,
     version,
@@ -141,11 +241,14 @@ impl_consensus_encoding!(Reject, message, ccode, reason, hash);
 
 #[cfg(test)]
 mod tests {
-    use super::{Reject, RejectReason, VersionMessage};
+    use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+
+    use super::{Reject, RejectReason, VersionMessage, VersionMessageBuilder, USER_AGENT};
     use crate::consensus::encode::{deserialize, serialize};
     use crate::hashes::sha256d;
     use crate::internal_macros::hex;
-    use crate::network::constants::ServiceFlags;
+    use crate::network::address::Address;
+    use crate::network::constants::{self, ServiceFlags};
 
     #[test]
     fn version_message_test() {
@@ -167,6 +270,57 @@ mod tests {
         assert_eq!(serialize(&real_decode), from_sat);
     }
 
+    #[test]
+    fn version_message_builder_test() {
+        let socket = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(111, 222, 123, 4)), 9333);
+        let receiver = Address::new(&socket, ServiceFlags::NETWORK);
+
+        let msg = VersionMessageBuilder::new(receiver.clone(), 42).build();
+        assert_eq!(msg.version, constants::LITECOIN_PROTOCOL_VERSION);
+        assert_eq!(msg.services, ServiceFlags::WITNESS | ServiceFlags::MWEB);
+        assert!(msg.timestamp > 0);
+        assert_eq!(msg.receiver, receiver);
+        assert_eq!(msg.sender.services, msg.services);
+        assert_eq!(msg.sender.address, [0; 8]);
+        assert_eq!(msg.nonce, 42);
+        assert_eq!(msg.user_agent, USER_AGENT);
+        assert!(msg.user_agent.starts_with("/rust-litecoin:"));
+        assert_eq!(msg.start_height, 0);
+        assert!(!msg.relay);
+
+        let msg = VersionMessageBuilder::new(receiver, 42)
+            .version(constants::PROTOCOL_VERSION)
+            .services(ServiceFlags::NONE)
+            .timestamp(1401217254)
+            .user_agent("/crawler:0.1/")
+            .start_height(2_500_000)
+            .relay(true)
+            .build();
+        assert_eq!(msg.version, constants::PROTOCOL_VERSION);
+        assert_eq!(msg.services, ServiceFlags::NONE);
+        assert_eq!(msg.sender.services, ServiceFlags::NONE);
+        assert_eq!(msg.timestamp, 1401217254);
+        assert_eq!(msg.user_agent, "/crawler:0.1/");
+        assert_eq!(msg.start_height, 2_500_000);
+        assert!(msg.relay);
+
+        let decoded: VersionMessage = deserialize(&serialize(&msg)).unwrap();
+        assert_eq!(decoded, msg);
+    }
+
+    #[test]
+    #[cfg(feature = "rand-std")]
+    fn version_message_builder_random_nonce() {
+        let socket = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(111, 222, 123, 4)), 9333);
+        let receiver = Address::new(&socket, ServiceFlags::NETWORK);
+
+        let a = VersionMessageBuilder::with_random_nonce(receiver.clone()).build();
+        let b = VersionMessageBuilder::with_random_nonce(receiver.clone()).build();
+        assert_ne!(a.nonce, b.nonce);
+        assert_eq!(a.receiver, receiver);
+        assert_eq!(a.version, constants::LITECOIN_PROTOCOL_VERSION);
+    }
+
     #[test]
     fn reject_message_test() {
         let reject_tx_conflict = hex!("027478121474786e2d6d656d706f6f6c2d636f6e666c69637405df54d3860b3c41806a3546ab48279300affacf4b88591b229141dcf2f47004");
@@ -0,0 +1,249 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Incremental decoding of network messages.
+//!
+//! This module provides [`StreamDecoder`], a push-based decoder for [`RawNetworkMessage`]s.
+//! Bytes are fed in as they arrive (e.g. from a non-blocking socket in an event loop) and
+//! complete messages are returned once enough data has been buffered.
+//!
+
+use core::fmt;
+
+use bitcoin_internals::write_err;
+
+use crate::consensus::encode;
+use crate::io;
+use crate::network::constants::Magic;
+use crate::network::message::{RawNetworkMessage, MAX_MSG_SIZE};
+
+/// Length of the message header: magic (4), command (12), payload length (4) and checksum (4).
+pub const HEADER_SIZE: usize = 24;
+
+/// Accumulates bytes from a stream and yields complete [`RawNetworkMessage`]s.
+///
+/// A message is rejected as soon as its header is available if the magic does not match or
+/// the announced payload exceeds the configured maximum, so that a misbehaving peer cannot
+/// make us buffer an arbitrary amount of data. Such errors leave the stream out of sync and
+/// the connection should be dropped. Messages that fail to decode (e.g. due to a bad
+/// checksum) are discarded before the error is returned.
+///
+/// # Examples
+///
+/// ```
+/// use bitcoin::consensus::encode;
+/// use bitcoin::network::constants::Magic;
+/// use bitcoin::network::message::{NetworkMessage, RawNetworkMessage};
+/// use bitcoin::network::stream::StreamDecoder;
+///
+/// let msg = RawNetworkMessage { magic: Magic::BITCOIN, payload: NetworkMessage::Verack };
+/// let bytes = encode::serialize(&msg);
+///
+/// let mut decoder = StreamDecoder::new(Magic::BITCOIN);
+/// decoder.push(&bytes[..10]);
+/// assert_eq!(decoder.next_message().unwrap(), None);
+/// decoder.push(&bytes[10..]);
+/// assert_eq!(decoder.next_message().unwrap(), Some(msg));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StreamDecoder {
+    magic: Magic,
+    max_payload_size: usize,
+    buffer: Vec<u8>,
+}
+
+impl StreamDecoder {
+    /// Creates a decoder for the network identified by `magic`, limiting payloads to [`MAX_MSG_SIZE`].
+    pub fn new(magic: Magic) -> Self {
+        StreamDecoder { magic, max_payload_size: MAX_MSG_SIZE, buffer: Vec::new() }
+    }
+
+    /// Sets the maximum accepted payload size in bytes.
+    pub fn with_max_payload_size(mut self, max_payload_size: usize) -> Self {
+        self.max_payload_size = max_payload_size;
+        self
+    }
+
+    /// Appends bytes received from the stream to the internal buffer.
+    pub fn push(&mut self, bytes: &[u8]) { self.buffer.extend_from_slice(bytes); }
+
+    /// Returns the next complete message, or `None` if more bytes are needed.
+    ///
+    /// Call this repeatedly after each [`push`](Self::push) until it returns `None`, a single
+    /// push may contain several messages.
+    pub fn next_message(&mut self) -> Result<Option<RawNetworkMessage>, Error> {
+        let frame_len = match frame_len(&self.buffer, self.magic, self.max_payload_size)? {
+            Some(len) if len <= self.buffer.len() => len,
+            _ => return Ok(None),
+        };
+
+        let message = encode::deserialize(&self.buffer[..frame_len]);
+        self.buffer.drain(..frame_len);
+        Ok(Some(message?))
+    }
+
+    /// Returns the number of buffered bytes not yet consumed as a message.
+    pub fn buffered_len(&self) -> usize { self.buffer.len() }
+}
+
+/// Validates the message header at the start of `buf` and returns the total frame length.
+///
+/// Returns `None` if `buf` does not yet hold a full header.
+pub(crate) fn frame_len(
+    buf: &[u8],
+    magic: Magic,
+    max_payload_size: usize,
+) -> Result<Option<usize>, Error> {
+    if buf.len() < HEADER_SIZE {
+        return Ok(None);
+    }
+
+    let actual = Magic::from_bytes([buf[0], buf[1], buf[2], buf[3]]);
+    if actual != magic {
+        return Err(Error::WrongMagic { expected: magic, actual });
+    }
+
+    let size = u32::from_le_bytes([buf[16], buf[17], buf[18], buf[19]]) as usize;
+    if size > max_payload_size {
+        return Err(Error::OversizedPayload { size, max: max_payload_size });
+    }
+
+    Ok(Some(HEADER_SIZE + size))
+}
+
+/// An error returned when decoding a stream of network messages.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// An I/O error on the underlying transport.
+    Io(io::Error),
+    /// The message was sent for a different network.
+    WrongMagic {
+        /// The magic the decoder was configured with.
+        expected: Magic,
+        /// The magic found in the message header.
+        actual: Magic,
+    },
+    /// The header announced a payload larger than the configured maximum.
+    OversizedPayload {
+        /// The announced payload size.
+        size: usize,
+        /// The maximum accepted payload size.
+        max: usize,
+    },
+    /// The message could not be decoded (including checksum mismatch).
+    Decode(encode::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Io(ref e) => write_err!(f, "IO error"; e),
+            Error::WrongMagic { expected, actual } =>
+                write!(f, "wrong network magic: expected {}, actual {}", expected, actual),
+            Error::OversizedPayload { size, max } =>
+                write!(f, "oversized payload: announced {}, maximum {}", size, max),
+            Error::Decode(ref e) => write_err!(f, "message decoding error"; e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use self::Error::*;
+
+        match self {
+            Io(e) => Some(e),
+            Decode(e) => Some(e),
+            WrongMagic { .. } | OversizedPayload { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self { Error::Io(error) }
+}
+
+impl From<encode::Error> for Error {
+    fn from(error: encode::Error) -> Self { Error::Decode(error) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::message::NetworkMessage;
+
+    fn ping(nonce: u64) -> RawNetworkMessage {
+        RawNetworkMessage { magic: Magic::BITCOIN, payload: NetworkMessage::Ping(nonce) }
+    }
+
+    #[test]
+    fn byte_by_byte() {
+        let mut decoder = StreamDecoder::new(Magic::BITCOIN);
+        let bytes = encode::serialize(&ping(1));
+
+        for &byte in &bytes[..bytes.len() - 1] {
+            decoder.push(&[byte]);
+            assert_eq!(decoder.next_message().unwrap(), None);
+        }
+        decoder.push(&bytes[bytes.len() - 1..]);
+        assert_eq!(decoder.next_message().unwrap(), Some(ping(1)));
+        assert_eq!(decoder.buffered_len(), 0);
+    }
+
+    #[test]
+    fn several_messages_in_one_push() {
+        let mut decoder = StreamDecoder::new(Magic::BITCOIN);
+        let mut bytes = encode::serialize(&ping(1));
+        bytes.extend(encode::serialize(&ping(2)));
+        let partial = encode::serialize(&ping(3));
+        bytes.extend_from_slice(&partial[..5]);
+
+        decoder.push(&bytes);
+        assert_eq!(decoder.next_message().unwrap(), Some(ping(1)));
+        assert_eq!(decoder.next_message().unwrap(), Some(ping(2)));
+        assert_eq!(decoder.next_message().unwrap(), None);
+        assert_eq!(decoder.buffered_len(), 5);
+
+        decoder.push(&partial[5..]);
+        assert_eq!(decoder.next_message().unwrap(), Some(ping(3)));
+    }
+
+    #[test]
+    fn wrong_magic() {
+        let mut decoder = StreamDecoder::new(Magic::TESTNET);
+        decoder.push(&encode::serialize(&ping(1)));
+        match decoder.next_message() {
+            Err(Error::WrongMagic { expected, actual }) => {
+                assert_eq!(expected, Magic::TESTNET);
+                assert_eq!(actual, Magic::BITCOIN);
+            }
+            res => panic!("unexpected result: {:?}", res),
+        }
+    }
+
+    #[test]
+    fn oversized_payload() {
+        let mut decoder = StreamDecoder::new(Magic::BITCOIN).with_max_payload_size(4);
+        // Only the header is needed to reject the message.
+        decoder.push(&encode::serialize(&ping(1))[..HEADER_SIZE]);
+        match decoder.next_message() {
+            Err(Error::OversizedPayload { size: 8, max: 4 }) => {}
+            res => panic!("unexpected result: {:?}", res),
+        }
+    }
+
+    #[test]
+    fn bad_checksum_is_skipped() {
+        let mut decoder = StreamDecoder::new(Magic::BITCOIN);
+        let mut bytes = encode::serialize(&ping(1));
+        bytes[20] ^= 0xff;
+        decoder.push(&bytes);
+        decoder.push(&encode::serialize(&ping(2)));
+
+        match decoder.next_message() {
+            Err(Error::Decode(encode::Error::InvalidChecksum { .. })) => {}
+            res => panic!("unexpected result: {:?}", res),
+        }
+        assert_eq!(decoder.next_message().unwrap(), Some(ping(2)));
+    }
+}
@@ -253,6 +253,30 @@ impl CompactTarget {
 
     /// Returns the consensus encoded `u32` representation of this [`CompactTarget`].
     pub fn to_consensus(self) -> u32 { self.0 }
+
+    /// Computes the [`Work`] done to mine a block with this compact target.
+    ///
+    /// Matches Bitcoin Core's `GetBlockProof`: bits which decode to a negative or zero target, or
+    /// which overflow 256 bits, represent no work at all. This differs from
+    /// `Target::from_compact(self).to_work()`, which keeps the sign bit for small exponents and
+    /// wraps oversized exponents, so invalid bits could otherwise claim huge amounts of work.
+    pub fn to_work(self) -> Work {
+        // Decode the mantissa as `arith_uint256::SetCompact` does.
+        let size = self.0 >> 24;
+        let mut word = self.0 & 0x7F_FFFF;
+        if size <= 3 {
+            word >>= 8 * (3 - size);
+        }
+        let negative = word != 0 && self.0 & 0x80_0000 != 0;
+        let overflow =
+            word != 0 && (size > 34 || (word > 0xFF && size > 33) || (word > 0xFFFF && size > 32));
+
+        if word == 0 || negative || overflow {
+            return Work(U256::ZERO);
+        }
+        let target = if size <= 3 { U256::from(word) } else { U256::from(word) << (8 * (size - 3)) };
+        Target(target).to_work()
+    }
 }
 
 impl From<CompactTarget> for Target {
@@ -1533,6 +1557,44 @@ mod tests {
         assert_eq!(back, target)
     }
 
+    #[test]
+    fn compact_target_to_work() {
+        use crate::blockdata::constants::genesis_block;
+        use crate::network::constants::Network;
+
+        let mainnet = genesis_block(Network::Bitcoin).header.bits;
+        assert_eq!(mainnet.to_work(), Work(U256::from(0x1_0001_0001_u64)));
+        assert_eq!(mainnet.to_work(), Target::from_compact(mainnet).to_work());
+
+        // Litecoin's proof of work limit.
+        let ltc = CompactTarget::from_consensus(0x1e0ffff0);
+        assert_eq!(ltc.to_work(), Work(U256::from(0x10_0010_u64)));
+
+        let regtest = genesis_block(Network::Regtest).header.bits;
+        assert_eq!(regtest, CompactTarget::from_consensus(0x207fffff));
+        assert_eq!(regtest.to_work(), Work(U256::from(2_u64)));
+
+        // Negative and zero mantissas decode to a zero target.
+        assert_eq!(CompactTarget::from_consensus(0x04923456).to_work(), Work(U256::ZERO));
+        assert_eq!(CompactTarget::from_consensus(0x1d000000).to_work(), Work(U256::ZERO));
+
+        // Targets overflowing 256 bits are no work, not the maximum work.
+        assert_eq!(CompactTarget::from_consensus(0x23000001).to_work(), Work(U256::ZERO));
+        assert_eq!(CompactTarget::from_consensus(0x22000100).to_work(), Work(U256::ZERO));
+        assert_eq!(CompactTarget::from_consensus(0x21010000).to_work(), Work(U256::ZERO));
+        // Largest representable exponent for a one byte mantissa.
+        assert_ne!(CompactTarget::from_consensus(0x220000ff).to_work(), Work(U256::ZERO));
+
+        // Small exponents: the sign bit is checked after shifting the mantissa, as in Core.
+        assert_eq!(CompactTarget::from_consensus(0x02923456).to_work(), Work(U256::ZERO));
+        assert_eq!(CompactTarget::from_consensus(0x01803456).to_work(), Work(U256::ZERO));
+        assert_eq!(CompactTarget::from_consensus(0x03800000).to_work(), Work(U256::ZERO));
+        assert_eq!(
+            CompactTarget::from_consensus(0x02123456).to_work(),
+            Target(U256::from(0x1234_u32)).to_work()
+        );
+    }
+
     #[cfg(feature = "std")]
     #[test]
     fn work_log2() {